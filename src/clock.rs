@@ -0,0 +1,239 @@
+//! Calendar- and clock-aware helpers built on top of the pure radix codec.
+//!
+//! Everything here pulls in `chrono` (and `chrono-tz` for named timezones)
+//! and is only available behind the `clock` feature.
+
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+
+use crate::radix::{decode_timestamp, encode_timestamp};
+use crate::ZidError;
+
+/// Resolves an IANA timezone name such as `"Australia/Sydney"`.
+pub fn parse_timezone(name: &str) -> Result<Tz, ZidError> {
+    name.parse()
+        .map_err(|_| ZidError::InvalidTimezone(name.into()))
+}
+
+/// Resolves a naive date/time to a concrete instant in the given timezone,
+/// erroring out instead of panicking when the local time falls in a DST
+/// gap (doesn't exist) or a DST overlap (ambiguous).
+fn resolve_local_datetime<Z: TimeZone>(
+    tz: &Z,
+    naive: &NaiveDateTime,
+) -> Result<DateTime<Z>, ZidError> {
+    match tz.from_local_datetime(naive) {
+        LocalResult::Single(datetime) => Ok(datetime),
+        LocalResult::None => Err(ZidError::AmbiguousOrInvalidLocalTime(format!(
+            "'{}' does not exist in the supplied timezone",
+            naive
+        ))),
+        LocalResult::Ambiguous(earliest, latest) => Err(ZidError::AmbiguousOrInvalidLocalTime(
+            format!(
+                "'{}' is ambiguous in the supplied timezone (could be {} or {})",
+                naive, earliest, latest
+            ),
+        )),
+    }
+}
+
+/// The current Unix timestamp, to the second.
+pub fn now_timestamp() -> i64 {
+    Utc::now().timestamp()
+}
+
+/// Parses a `YYYY-MM-DD` date as midnight in `tz` and returns its timestamp.
+pub fn date_to_timestamp(input: &str, tz: Tz) -> Result<i64, ZidError> {
+    let date = NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .map_err(|e| ZidError::InvalidDateTime(format!("Failed to parse date: {}", e)))?;
+    let naive = date.and_hms_opt(0, 0, 0).expect("midnight is always valid");
+
+    Ok(resolve_local_datetime(&tz, &naive)?.timestamp())
+}
+
+/// Parses a `YYYY-MM-DDTHH:MM:SS` (or `YYYY-MM-DD HH:MM:SS`) date/time in
+/// `tz` and returns its timestamp.
+pub fn iso_date_to_timestamp(input: &str, tz: Tz) -> Result<i64, ZidError> {
+    let normalized = input.replacen(' ', "T", 1);
+    let naive = NaiveDateTime::parse_from_str(&normalized, "%Y-%m-%dT%H:%M:%S")
+        .map_err(|e| ZidError::InvalidDateTime(format!("Failed to parse date: {}", e)))?;
+
+    Ok(resolve_local_datetime(&tz, &naive)?.timestamp())
+}
+
+/// Parses an offset-aware RFC 3339 timestamp and returns its timestamp.
+pub fn rfc3339_to_timestamp(input: &str) -> Result<i64, ZidError> {
+    DateTime::parse_from_rfc3339(input)
+        .map(|datetime| datetime.timestamp())
+        .map_err(|e| ZidError::InvalidDateTime(format!("Failed to parse date: {}", e)))
+}
+
+/// Parses an offset-aware RFC 2822 timestamp and returns its timestamp.
+pub fn rfc2822_to_timestamp(input: &str) -> Result<i64, ZidError> {
+    DateTime::parse_from_rfc2822(input)
+        .map(|datetime| datetime.timestamp())
+        .map_err(|e| ZidError::InvalidDateTime(format!("Failed to parse date: {}", e)))
+}
+
+/// Parses a human/relative date expression (eg `"2 days ago"`, `"yesterday"`,
+/// `"next monday"`) relative to now and returns its timestamp.
+pub fn relative_to_timestamp(input: &str) -> Result<i64, ZidError> {
+    let delta = parse_relative_duration(input)?;
+
+    Ok((Utc::now() + delta).timestamp())
+}
+
+/// Parses simple human-friendly relative time expressions into a `TimeDelta`.
+///
+/// Supports "now", "yesterday", "tomorrow", "next <weekday>", "N <unit> ago"
+/// and "in N <unit>", where `unit` is one of second(s), minute(s), hour(s),
+/// day(s) or week(s).
+fn parse_relative_duration(input: &str) -> Result<TimeDelta, ZidError> {
+    let trimmed = input.trim().to_lowercase();
+
+    match trimmed.as_str() {
+        "now" => return Ok(TimeDelta::zero()),
+        "yesterday" => return Ok(TimeDelta::days(-1)),
+        "tomorrow" => return Ok(TimeDelta::days(1)),
+        _ => {}
+    }
+
+    if let Some(rest) = trimmed.strip_suffix(" ago") {
+        let (amount, unit) = parse_amount_and_unit(rest)?;
+        return unit_to_delta(unit, -amount);
+    }
+
+    if let Some(rest) = trimmed.strip_prefix("in ") {
+        let (amount, unit) = parse_amount_and_unit(rest)?;
+        return unit_to_delta(unit, amount);
+    }
+
+    if let Some(weekday_name) = trimmed.strip_prefix("next ") {
+        let weekday = parse_weekday(weekday_name).ok_or_else(|| {
+            ZidError::InvalidDateTime(format!("Unrecognized weekday in '{}'", input))
+        })?;
+        return Ok(TimeDelta::days(days_until_next(weekday)));
+    }
+
+    Err(ZidError::InvalidDateTime(format!(
+        "Unrecognized relative time expression: '{}'",
+        input
+    )))
+}
+
+/// Splits an expression like "3 weeks" into its amount and unit.
+fn parse_amount_and_unit(input: &str) -> Result<(i64, &str), ZidError> {
+    let mut parts = input.split_whitespace();
+    let amount = parts
+        .next()
+        .ok_or_else(|| {
+            ZidError::InvalidDateTime("Missing amount in relative time expression".into())
+        })?
+        .parse::<i64>()
+        .map_err(|_| ZidError::InvalidDateTime("Amount must be a whole number".into()))?;
+    let unit = parts
+        .next()
+        .ok_or_else(|| {
+            ZidError::InvalidDateTime("Missing unit in relative time expression".into())
+        })?
+        .trim_end_matches('s');
+
+    Ok((amount, unit))
+}
+
+fn unit_to_delta(unit: &str, amount: i64) -> Result<TimeDelta, ZidError> {
+    match unit {
+        "second" => Ok(TimeDelta::seconds(amount)),
+        "minute" => Ok(TimeDelta::minutes(amount)),
+        "hour" => Ok(TimeDelta::hours(amount)),
+        "day" => Ok(TimeDelta::days(amount)),
+        "week" => Ok(TimeDelta::weeks(amount)),
+        other => Err(ZidError::InvalidDateTime(format!(
+            "Unrecognized time unit '{}'",
+            other
+        ))),
+    }
+}
+
+fn parse_weekday(input: &str) -> Option<Weekday> {
+    match input {
+        "monday" => Some(Weekday::Mon),
+        "tuesday" => Some(Weekday::Tue),
+        "wednesday" => Some(Weekday::Wed),
+        "thursday" => Some(Weekday::Thu),
+        "friday" => Some(Weekday::Fri),
+        "saturday" => Some(Weekday::Sat),
+        "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Number of days from today until the next occurrence of `target`,
+/// always at least 1 (ie "next monday" said on a Monday means in a week).
+fn days_until_next(target: Weekday) -> i64 {
+    let today = Utc::now().weekday();
+    let mut days_ahead =
+        target.num_days_from_monday() as i64 - today.num_days_from_monday() as i64;
+    if days_ahead <= 0 {
+        days_ahead += 7;
+    }
+
+    days_ahead
+}
+
+/// Generates a timestamp from a file's last-modified time, analogous to
+/// GNU date's `--reference`.
+#[cfg(feature = "std")]
+pub fn file_mtime_to_timestamp(path: &std::path::Path) -> Result<i64, ZidError> {
+    let metadata = std::fs::metadata(path).map_err(|e| {
+        ZidError::Io(format!(
+            "Failed to read metadata for '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let modified = metadata.modified().map_err(|e| {
+        ZidError::Io(format!(
+            "Platform does not support mtime for '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    let unix_time = modified
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|_| ZidError::Io("File's modification time is before the Unix epoch".into()))?
+        .as_secs();
+
+    i64::try_from(unix_time).map_err(|_| ZidError::TimestampOutOfRange(unix_time))
+}
+
+/// Decodes a zID into the `DateTime<Utc>` it encodes.
+pub fn decode_to_datetime(zid: &str, radix: u8) -> Result<DateTime<Utc>, ZidError> {
+    let timestamp = decode_timestamp(zid, radix)?;
+
+    DateTime::from_timestamp(timestamp, 0).ok_or(ZidError::TimestampOutOfRange(timestamp as u64))
+}
+
+/// Formats a zID as a `YYYY-MM-DD` date string in `tz`.
+pub fn zid_to_date_string(zid: &str, radix: u8, tz: Tz) -> Result<String, ZidError> {
+    let datetime = decode_to_datetime(zid, radix)?;
+
+    Ok(datetime.with_timezone(&tz).format("%Y-%m-%d").to_string())
+}
+
+/// Formats a zID as a `YYYY-MM-DDTHH:MM:SS` date/time string in `tz`.
+pub fn zid_to_iso_date_string(zid: &str, radix: u8, tz: Tz) -> Result<String, ZidError> {
+    let datetime = decode_to_datetime(zid, radix)?;
+
+    Ok(datetime
+        .with_timezone(&tz)
+        .format("%Y-%m-%dT%H:%M:%S")
+        .to_string())
+}
+
+/// Re-encodes a timestamp as a zID, forwarding to the pure radix codec.
+pub fn timestamp_to_zid(timestamp: i64, radix: u8) -> Result<String, ZidError> {
+    encode_timestamp(timestamp, radix)
+}
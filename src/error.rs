@@ -0,0 +1,58 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt;
+
+/// Errors produced while encoding or decoding a zID.
+#[derive(Debug)]
+pub enum ZidError {
+    /// The requested radix was outside the supported `10..=36` range.
+    InvalidRadix(u8),
+    /// A timestamp was negative and so cannot be encoded as a zID.
+    NegativeTimestamp(i64),
+    /// A zID string could not be parsed as a number in the given radix.
+    InvalidZid(String),
+    /// A value is outside the range of representable Unix timestamps (it
+    /// does not fit in an `i64`, or cannot be turned into a date/time).
+    TimestampOutOfRange(u64),
+    /// A date or time string could not be parsed.
+    #[cfg(feature = "clock")]
+    InvalidDateTime(String),
+    /// An IANA timezone name was not recognized.
+    #[cfg(feature = "clock")]
+    InvalidTimezone(String),
+    /// A local date/time does not exist (DST gap) or is ambiguous
+    /// (DST overlap) in the given timezone.
+    #[cfg(feature = "clock")]
+    AmbiguousOrInvalidLocalTime(String),
+    /// Reading a file's metadata or modification time failed.
+    #[cfg(feature = "std")]
+    Io(String),
+}
+
+impl fmt::Display for ZidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZidError::InvalidRadix(radix) => {
+                write!(f, "radix {} is outside the supported 10..=36 range", radix)
+            }
+            ZidError::NegativeTimestamp(ts) => {
+                write!(f, "timestamp {} is negative and cannot be encoded", ts)
+            }
+            ZidError::InvalidZid(zid) => write!(f, "'{}' is not a valid zID", zid),
+            ZidError::TimestampOutOfRange(ts) => {
+                write!(f, "value {} is outside the representable timestamp range", ts)
+            }
+            #[cfg(feature = "clock")]
+            ZidError::InvalidDateTime(message) => write!(f, "{}", message),
+            #[cfg(feature = "clock")]
+            ZidError::InvalidTimezone(name) => write!(f, "unrecognized IANA timezone '{}'", name),
+            #[cfg(feature = "clock")]
+            ZidError::AmbiguousOrInvalidLocalTime(message) => write!(f, "{}", message),
+            #[cfg(feature = "std")]
+            ZidError::Io(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ZidError {}
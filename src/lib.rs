@@ -0,0 +1,35 @@
+//! Core encode/decode logic for Zettelkasten IDs (zIDs).
+//!
+//! A zID is a Unix timestamp written out in a configurable radix (10 to 36)
+//! so that it sorts lexically the same way it sorts numerically, while
+//! staying short enough to use as a note filename.
+//!
+//! The pure radix conversion ([`encode_timestamp`], [`decode_timestamp`])
+//! needs only `core` and `alloc`, so it's available in `no_std` builds.
+//! Everything that talks to a wall clock or parses calendar dates pulls in
+//! `chrono`/`chrono-tz` and lives behind the `clock` feature, which (along
+//! with `std`) is enabled by default.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+mod error;
+mod radix;
+
+#[cfg(feature = "clock")]
+mod clock;
+
+pub use error::ZidError;
+pub use radix::{clamp_radix, decode_timestamp, encode_timestamp, MAX_RADIX, MIN_RADIX};
+
+#[cfg(feature = "clock")]
+pub use clock::{
+    date_to_timestamp, decode_to_datetime, iso_date_to_timestamp, now_timestamp, parse_timezone,
+    relative_to_timestamp, rfc2822_to_timestamp, rfc3339_to_timestamp, timestamp_to_zid,
+    zid_to_date_string, zid_to_iso_date_string,
+};
+
+#[cfg(all(feature = "clock", feature = "std"))]
+pub use clock::file_mtime_to_timestamp;
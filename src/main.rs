@@ -1,118 +1,193 @@
-use anyhow::Result;
-use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono_tz::Tz;
 use clap::Parser;
-use radix_fmt::radix;
+use zettelkasten_id_generator::{
+    clamp_radix, date_to_timestamp, encode_timestamp, file_mtime_to_timestamp,
+    iso_date_to_timestamp, now_timestamp, parse_timezone, relative_to_timestamp,
+    rfc2822_to_timestamp, rfc3339_to_timestamp, zid_to_date_string, zid_to_iso_date_string,
+    ZidError,
+};
 
 #[derive(Parser, Debug, Clone)]
 #[command(about, long_about = None)]
 struct Cli {
-    /// A value to be used when generating or parsing a zID
+    /// A value to be used when generating or parsing a zID. Pass "-" (or
+    /// use --batch) to read newline-separated values from stdin instead
     input: Option<String>,
     /// The radix base used for conversion between 10 and 36
     #[clap(default_value = "36")]
     #[arg(short, long, value_parser = clap::value_parser!(u8).range(10..37))]
     radix: u8,
 
+    /// The IANA timezone (eg "Australia/Sydney") used when interpreting a
+    /// local date/time on the `from_*` paths, and when formatting a
+    /// timestamp back to a string on the `to_*` paths
+    #[arg(long, default_value = "UTC")]
+    timezone: String,
+
+    /// Reads newline-separated values from stdin and applies the selected
+    /// conversion to each, writing one result per line
+    #[arg(long)]
+    batch: bool,
+
     /// Generates a zID from a supplied date
-    #[arg(long, requires = "input")]
+    #[arg(long)]
     from_date: bool,
 
     /// Generates a zID from a supplied date in ISO format
     /// eg, 2001-01-01T07:35:42
-    #[arg(long, requires = "input")]
+    #[arg(long)]
     from_iso_date: bool,
 
+    /// Generates a zID from an offset-aware RFC 3339 timestamp
+    /// eg, 2001-01-01T07:35:42+10:00
+    #[arg(long)]
+    from_rfc3339: bool,
+
+    /// Generates a zID from an offset-aware RFC 2822 timestamp
+    /// eg, Mon, 1 Jan 2001 07:35:42 +1000
+    #[arg(long)]
+    from_rfc2822: bool,
+
+    /// Generates a zID from a file's last-modified time, analogous to
+    /// GNU date's --reference
+    #[arg(long, value_name = "PATH")]
+    from_file: Option<PathBuf>,
+
+    /// Generates a zID from a human/relative date expression relative to
+    /// now, eg "2 days ago", "yesterday", "in 3 weeks", "next monday"
+    #[arg(long)]
+    from_relative: bool,
+
     /// Converts a zID back to date in the format "YYYY-MM-DD"
-    #[arg(long, requires = "input")]
+    #[arg(long)]
     to_date: bool,
 
     /// Converts a zID back to date in the format "YYYY-MM-DDTHH:MM:SS"
-    #[arg(long, requires = "input")]
+    #[arg(long)]
     to_iso_date: bool,
 }
 
-fn main() -> Result<()> {
-    let args = Cli::parse();
-    let base = get_base_radix(args.radix)?;
-
-    let input = if args.from_date || args.from_iso_date || args.to_date {
-        args.input.expect("No input provided").trim().to_owned()
-    } else {
-        "".to_owned()
-    };
-
-    let zid = if args.from_date {
-        zid_from_date(base, input)
-    } else if args.from_iso_date {
-        zid_from_iso_date(base, input)
-    } else if args.to_date {
-        zid_to_date(base, input)
-    } else if args.to_iso_date {
-        zid_to_iso_date(base, input)
-    } else {
-        now_to_zid(base)
-    }
-    .expect("Failed to generate output");
-
-    print!("{}", zid);
-
-    Ok(())
+/// The conversion to apply to a single value, selected by the CLI flags.
+enum Mode {
+    FromDate,
+    FromIsoDate,
+    FromRfc3339,
+    FromRfc2822,
+    FromRelative,
+    ToDate,
+    ToIsoDate,
 }
 
-fn get_base_radix(input: u8) -> Result<u8> {
-    let max_base = 36;
-    let min_base = 10;
-
-    Ok(min_base.max(max_base.min(input)))
-}
+impl Mode {
+    fn from_flags(args: &Cli) -> Option<Mode> {
+        if args.from_date {
+            Some(Mode::FromDate)
+        } else if args.from_iso_date {
+            Some(Mode::FromIsoDate)
+        } else if args.from_rfc3339 {
+            Some(Mode::FromRfc3339)
+        } else if args.from_rfc2822 {
+            Some(Mode::FromRfc2822)
+        } else if args.from_relative {
+            Some(Mode::FromRelative)
+        } else if args.to_date {
+            Some(Mode::ToDate)
+        } else if args.to_iso_date {
+            Some(Mode::ToIsoDate)
+        } else {
+            None
+        }
+    }
 
-/// Generates a Zettelkasten ID using the current date and time (to the second).
-fn now_to_zid(base: u8) -> Result<String> {
-    let unix_time = Utc::now().timestamp();
-    let zid = radix(unix_time, base);
-    Ok(zid.to_string())
+    fn apply(&self, value: &str, base: u8, tz: Tz) -> Result<String, ZidError> {
+        match self {
+            Mode::FromDate => date_to_timestamp(value, tz).and_then(|ts| encode_timestamp(ts, base)),
+            Mode::FromIsoDate => {
+                iso_date_to_timestamp(value, tz).and_then(|ts| encode_timestamp(ts, base))
+            }
+            Mode::FromRfc3339 => {
+                rfc3339_to_timestamp(value).and_then(|ts| encode_timestamp(ts, base))
+            }
+            Mode::FromRfc2822 => {
+                rfc2822_to_timestamp(value).and_then(|ts| encode_timestamp(ts, base))
+            }
+            Mode::FromRelative => {
+                relative_to_timestamp(value).and_then(|ts| encode_timestamp(ts, base))
+            }
+            Mode::ToDate => zid_to_date_string(value, base, tz),
+            Mode::ToIsoDate => zid_to_iso_date_string(value, base, tz),
+        }
+    }
 }
 
-/// Generates a Zettelkasten ID from a Date.
-
-fn zid_from_date(base: u8, input: String) -> Result<String> {
-    let date = NaiveDate::parse_from_str(&input, "%Y-%m-%d").expect("Failed to parse date");
-    let datetime = date.and_hms_opt(0, 0, 0).expect("Failed to parse date");
-    let unix_time = Utc.from_local_datetime(&datetime).unwrap().timestamp();
-
-    let zid = radix(unix_time as u64, base);
+fn main() -> Result<()> {
+    let args = Cli::parse();
+    let base = clamp_radix(args.radix);
+    let tz = parse_timezone(&args.timezone).map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    if let Some(path) = &args.from_file {
+        let zid = file_mtime_to_timestamp(path)
+            .and_then(|ts| encode_timestamp(ts, base))
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        print!("{}", zid);
+        return Ok(());
+    }
 
-    Ok(zid.to_string())
-}
+    let batch_mode = args.batch || args.input.as_deref() == Some("-");
 
-/// Generates a Zettelkasten ID from an ISO8601 formatted Date.
-fn zid_from_iso_date(base: u8, input: String) -> Result<String> {
-    let datetime =
-        NaiveDateTime::parse_from_str(&input, "%Y-%m-%dT%H:%M:%S").expect("Failed to parse date");
+    if batch_mode {
+        let mode = Mode::from_flags(&args)
+            .context("--batch requires a conversion flag (eg --from-date, --to-iso-date)")?;
+        return run_batch(&mode, base, tz);
+    }
 
-    let unix_time = Utc.from_local_datetime(&datetime).unwrap().timestamp();
+    let zid = match Mode::from_flags(&args) {
+        Some(mode) => {
+            let input = args.input.context("No input provided")?.trim().to_owned();
+            mode.apply(&input, base, tz)
+        }
+        None => encode_timestamp(now_timestamp(), base),
+    }
+    .map_err(|e| anyhow::anyhow!("{}", e))?;
 
-    let zid = radix(unix_time as u64, base);
+    print!("{}", zid);
 
-    Ok(zid.to_string())
+    Ok(())
 }
 
-/// Returns the YYYY-MM-DD date string from a supplied zID.
-fn zid_to_date(base: u8, input: String) -> Result<String> {
-    let timestamp = u64::from_str_radix(&input, base as u32).expect("Failed to parse input");
-    let naive_datetime =
-        NaiveDateTime::from_timestamp_opt(timestamp as i64, 0).expect("Failed to parse zid");
-    let datetime: DateTime<Utc> = DateTime::from_naive_utc_and_offset(naive_datetime, Utc);
-
-    Ok(datetime.format("%Y-%m-%d").to_string())
-}
+/// Reads newline-separated values from stdin and applies `mode` to each,
+/// writing one result per line to stdout. A malformed line emits an error
+/// to stderr and the run continues rather than aborting; the process exits
+/// non-zero if any line failed.
+fn run_batch(mode: &Mode, base: u8, tz: Tz) -> Result<()> {
+    use std::io::{self, BufRead, Write};
+
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+    let mut had_failure = false;
+
+    for line in stdin.lock().lines() {
+        let line = line.context("Failed to read a line from stdin")?;
+        let value = line.trim();
+        if value.is_empty() {
+            continue;
+        }
+
+        match mode.apply(value, base, tz) {
+            Ok(zid) => writeln!(stdout, "{}", zid)?,
+            Err(e) => {
+                had_failure = true;
+                eprintln!("{}: {}", value, e);
+            }
+        }
+    }
 
-/// Returns the YYYY-MM-DDTHH:MM:SS date string from a supplied zID.
-fn zid_to_iso_date(base: u8, input: String) -> Result<String> {
-    let timestamp = u64::from_str_radix(&input, base as u32).expect("Failed to parse input");
-    let naive_datetime =
-        NaiveDateTime::from_timestamp_opt(timestamp as i64, 0).expect("Failed to parse zid");
-    let datetime: DateTime<Utc> = DateTime::from_naive_utc_and_offset(naive_datetime, Utc);
+    if had_failure {
+        std::process::exit(1);
+    }
 
-    Ok(datetime.format("%Y-%m-%dT%H:%M:%S").to_string())
+    Ok(())
 }
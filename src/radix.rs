@@ -0,0 +1,40 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
+use radix_fmt::radix;
+
+use crate::ZidError;
+
+/// Smallest radix a zID may be encoded/decoded in.
+pub const MIN_RADIX: u8 = 10;
+/// Largest radix a zID may be encoded/decoded in.
+pub const MAX_RADIX: u8 = 36;
+
+/// Clamps a requested radix into the supported `MIN_RADIX..=MAX_RADIX` range.
+pub fn clamp_radix(radix: u8) -> u8 {
+    MIN_RADIX.max(MAX_RADIX.min(radix))
+}
+
+/// Encodes a Unix timestamp as a zID string in the given radix.
+pub fn encode_timestamp(timestamp: i64, radix: u8) -> Result<String, ZidError> {
+    if !(MIN_RADIX..=MAX_RADIX).contains(&radix) {
+        return Err(ZidError::InvalidRadix(radix));
+    }
+    if timestamp < 0 {
+        return Err(ZidError::NegativeTimestamp(timestamp));
+    }
+
+    Ok(radix_fmt::radix(timestamp as u64, radix).to_string())
+}
+
+/// Decodes a zID string back into the Unix timestamp it encodes.
+pub fn decode_timestamp(zid: &str, radix: u8) -> Result<i64, ZidError> {
+    if !(MIN_RADIX..=MAX_RADIX).contains(&radix) {
+        return Err(ZidError::InvalidRadix(radix));
+    }
+
+    let timestamp = u64::from_str_radix(zid, radix as u32)
+        .map_err(|_| ZidError::InvalidZid(zid.to_string()))?;
+
+    i64::try_from(timestamp).map_err(|_| ZidError::TimestampOutOfRange(timestamp))
+}